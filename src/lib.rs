@@ -10,13 +10,13 @@
 //! struct User {
 //!     id: usize,
 //!     username: String,
-//!     session_token: Secret<String>,
+//!     session_token: Token<[u8; 32]>,
 //! }
 //!
-//! let alice = User{
+//! let alice = User {
 //!     id: 1,
 //!     username: "alice".to_owned(),
-//!     session_token: Secret::new("no one should see this".to_owned()),
+//!     session_token: Token::generate(),
 //! };
 //!
 //! println!("Now talking to: {:?}", alice);
@@ -24,27 +24,18 @@
 //!
 //! Tokens are generated using the system's random number generator.
 //!
-//! By default, this crate does not prevent the token from leaking, e.g. into logs.
-//! You can use the [`sec` crate](https://github.com/49nord/sec-rs) in combination with this crate
-//! to prevent leaks:
-//!
-//! ```rust
-//! use sec::Secret;
-//! use tok::Token;
-//!
-//! type SecretToken = Secret<Token>;
-//!
-//! let token : SecretToken = Secret::new(Token::<[u8; 32]>::generate());
-//!
-//! println!("Generated token: {:?}", alice);
-//! ```
-//!
-//! This will yield the following output:
+//! By default, `Token`'s own `Debug` and `Display` already redact its
+//! contents -- the example above prints the session token as `Token(...)`,
+//! not the underlying bytes. This will yield the following output:
 //!
 //! ```raw
-//! Generated token: "..."
+//! Now talking to: User { id: 1, username: "alice", session_token: Token(...) }
 //! ```
 //!
+//! Use [`Token::reveal`]/[`Token::reveal_bytes`] when you genuinely need
+//! the raw contents, or see the [`sec` crate](https://github.com/49nord/sec-rs)
+//! if you'd rather wrap values from crates that don't redact themselves.
+//!
 //! ## Serde support (`deserialize`/`serialize` features)
 //!
 //! If the `deserialize` feature is enabled, any `Secret<T>` will automatically
@@ -66,7 +57,10 @@
 //!
 //! If protecting cryptographic secrets in-memory from stackdumps and similar
 //! is a concern, have a look at the [secrets](https://crates.io/crates/secrets)
-//! crate or similar crates.
+//! crate or similar crates, or enable this crate's own `zeroize` feature,
+//! which wipes a token's backing bytes as soon as it is dropped. Backing
+//! stores with drop glue of their own (`String`, `Vec<u8>`, ...) are not
+//! wiped.
 
 #![no_std]
 
@@ -74,8 +68,18 @@
 extern crate serde;
 
 extern crate constant_time_eq;
+extern crate rand_core;
+
+#[cfg(feature = "getrandom")]
 extern crate rand;
 
+#[cfg(feature = "digest")]
+extern crate digest;
+#[cfg(feature = "digest")]
+extern crate generic_array;
+#[cfg(feature = "digest")]
+extern crate sha2;
+
 #[cfg(feature = "serialize")]
 use serde::Serializer;
 
@@ -95,26 +99,134 @@ use std::hash::{Hasher, Hash};
 #[cfg(not(feature = "std"))]
 use core::hash::{Hasher, Hash};
 
-use self::rand::Rng;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+
+use self::rand_core::{CryptoRng, RngCore};
 use self::constant_time_eq::constant_time_eq;
 
-#[derive(Clone, Debug)]
+/// A session token holding a secret value of type `S`.
+///
+/// By default, `Debug` and `Display` never print the held value -- use
+/// [`reveal`](Token::reveal) or [`reveal_bytes`](Token::reveal_bytes) when
+/// the raw contents are genuinely needed, enable the `fmt-unsafe` feature to
+/// restore the old, leaking `Debug` output, or enable the `display-hex`
+/// feature to have `Display` print the contents as hex.
+#[derive(Clone)]
 pub struct Token<S>(S);
 
-impl<S: rand::Rand> Token<S> {
+impl<S> Token<S> {
+    /// Returns a reference to the token's raw contents.
+    ///
+    /// Named `reveal` rather than e.g. `as_inner` so that call sites make
+    /// it obvious a secret is being unwrapped.
     #[inline]
-    pub unsafe fn create(data: S) -> Token<S> {
-        Token(data)
+    pub fn reveal(&self) -> &S {
+        &self.0
     }
+}
 
+impl<S: AsRef<[u8]>> Token<S> {
+    /// Returns the token's raw contents as a byte slice.
     #[inline]
-    pub fn generate() -> Token<S> {
-        let mut rng = rand::thread_rng();
+    pub fn reveal_bytes(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+#[cfg(not(feature = "fmt-unsafe"))]
+impl<S> fmt::Debug for Token<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Token(...)")
+    }
+}
+
+#[cfg(feature = "fmt-unsafe")]
+impl<S: fmt::Debug> fmt::Debug for Token<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Token").field(&self.0).finish()
+    }
+}
 
-        Token(rng.gen())
+/// By default `Display` stays redacted like `Debug` does; it prints hex
+/// (see [`impl Display for Token`](#impl-Display-for-Token%3CS%3E) below)
+/// only once the `display-hex` feature is enabled.
+/// This is a deliberate, separate opt-in from `fmt-unsafe` -- enabling
+/// `fmt-unsafe` (which restores a leaking `Debug`) should not silently make
+/// `Display` leak too, and vice versa.
+#[cfg(not(feature = "display-hex"))]
+impl<S> fmt::Display for Token<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Token(...)")
     }
 }
 
+/// With `display-hex` enabled, `Display` prints the token as lower-case hex
+/// rather than the redacted placeholder -- see [`to_hex`](Token::to_hex) for
+/// the equivalent that needs only the `std` feature.
+#[cfg(feature = "display-hex")]
+impl<S: AsRef<[u8]>> fmt::Display for Token<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0.as_ref() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<S> Token<S> {
+    #[inline]
+    pub unsafe fn create(data: S) -> Token<S> {
+        Token(data)
+    }
+}
+
+// `generate`/`generate_with` are generated per array length, the same way
+// the serde and hex/base64 encoding impls are: without const generics,
+// `[u8; N]: Default` only exists (via libcore's own macro-generated impls)
+// for `N` up to 32, which would make `generate` silently unavailable for
+// the crate's own `[u8; 48]`/`[u8; 64]` sizes. Building the array directly
+// with a literal-length `[0u8; $len]` sidesteps the `Default` bound
+// entirely and keeps all six supported lengths on equal footing.
+macro_rules! impl_fixed_width_generate {
+    ($($len:expr),+ $(,)*) => {
+        $(
+            impl Token<[u8; $len]> {
+                /// Generates a new token using the supplied cryptographically
+                /// secure RNG.
+                ///
+                /// This is the `no_std`-friendly entry point: it only depends
+                /// on `rand_core`, so embedded and WASM targets can plug in
+                /// whatever `CryptoRng` they have (a hardware TRNG,
+                /// `getrandom`'s `no_std` backend, ...) without pulling in
+                /// `std`. Bounding on `CryptoRng` rather than plain `RngCore`
+                /// means a non-cryptographic RNG can't be passed in by
+                /// accident.
+                pub fn generate_with<R: RngCore + CryptoRng>(rng: &mut R) -> Token<[u8; $len]> {
+                    let mut data = [0u8; $len];
+                    rng.fill_bytes(&mut data);
+                    Token(data)
+                }
+
+                /// Generates a new token using the system's default CSPRNG.
+                ///
+                /// Requires the `getrandom` feature, which pulls in `std` and
+                /// `rand`'s thread-local RNG. Without it, use
+                /// [`generate_with`](Token::generate_with).
+                #[cfg(feature = "getrandom")]
+                #[inline]
+                pub fn generate() -> Token<[u8; $len]> {
+                    Self::generate_with(&mut rand::thread_rng())
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_width_generate!(16, 20, 24, 32, 48, 64);
+
 impl<S: AsRef<[u8]>> PartialEq for Token<S> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -131,56 +243,528 @@ impl<S: AsRef<[u8]>> Token<S> {
     }
 }
 
+impl<S: AsRef<[u8]>> Token<S> {
+    /// Compares two tokens in constant time.
+    ///
+    /// Unlike a naive lexicographic comparison, this walks every byte of the
+    /// shorter token unconditionally instead of stopping at the first
+    /// difference, so the time taken does not leak where (or whether) the
+    /// two tokens differ. Length is only folded into the result once the
+    /// full scan has completed.
+    pub fn constant_time_cmp(&self, other: &Self) -> cmp::Ordering {
+        let a = self.0.as_ref();
+        let b = other.0.as_ref();
+        let len = cmp::min(a.len(), b.len());
+
+        let mut result: i8 = 0;
+        for i in 0..len {
+            let gt = (a[i] > b[i]) as i8;
+            let lt = (a[i] < b[i]) as i8;
+            let undecided = (result == 0) as i8;
+            result |= (gt - lt) * undecided;
+        }
+
+        match result {
+            0 => a.len().cmp(&b.len()),
+            r if r > 0 => cmp::Ordering::Greater,
+            _ => cmp::Ordering::Less,
+        }
+    }
+}
+
+impl<S: AsRef<[u8]>> Ord for Token<S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.constant_time_cmp(other)
+    }
+}
+
 impl<S: AsRef<[u8]>> PartialOrd for Token<S> {
+    #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        let len_self = self.0.as_ref().len();
-        let len_other = other.0.as_ref().len();
-
-        if len_self == len_other {
-            Some(
-                self.0
-                    .as_ref()
-                    .iter()
-                    .zip(other.0.as_ref().iter())
-                    .filter_map(|(s, o)| s.partial_cmp(o))
-                    .skip_while(|&ord| ord == cmp::Ordering::Equal)
-                    .next()
-                    .unwrap_or(cmp::Ordering::Equal),
-            )
-        } else {
-            // if lengths don't match up, simply compare based on length
-            len_self.partial_cmp(&len_other)
+        Some(self.cmp(other))
+    }
+}
+
+
+// `Token<[u8; N]>` gets a dedicated, fixed-width serde representation instead
+// of forwarding to `[u8; N]`'s own `Serialize`/`Deserialize` impls, which
+// treat the array as a sequence (per-element framing in binary formats, an
+// array of integers in JSON). On the wire we want a single length-checked
+// blob: `serialize_bytes` (no length prefix) for binary formats, and a hex
+// string when `serializer.is_human_readable()`. The hex path needs the `std`
+// feature; without it, human-readable formats get the byte blob as well and
+// hex strings are not accepted on deserialization. Const generics aren't
+// available, so the impls are generated per-length by macro, the same way
+// `serde` itself historically implemented `Serialize`/`Deserialize` for
+// arrays.
+//
+// This is deliberately `Token<[u8; N]>`-only, not a blanket `impl<S: ..>
+// for Token<S>`: a generic impl would overlap with these concrete ones
+// (`E0119`) since `[u8; N]` could satisfy both, and stable Rust has no
+// specialization to let the concrete impl win. So backing stores other
+// than the fixed array lengths listed below (`Token<String>`, `Token<Vec<u8>>`,
+// ...) don't get `Serialize`/`Deserialize` from this crate; if you need
+// it for one of those, implement it directly against `S`'s own impl, e.g.
+// `self.reveal().serialize(serializer)`.
+#[cfg(feature = "std")]
+fn hex_encode(bytes: &[u8]) -> std::string::String {
+    use std::fmt::Write;
+
+    let mut out = std::string::String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        write!(out, "{:02x}", byte).expect("writing to a String cannot fail");
+    }
+    out
+}
+
+fn hex_decode(s: &str, out: &mut [u8]) -> Result<(), &'static str> {
+    if s.len() != out.len() * 2 {
+        return Err("hex string has the wrong length");
+    }
+
+    for (i, chunk) in s.as_bytes().chunks(2).enumerate() {
+        let hi = (chunk[0] as char).to_digit(16).ok_or("invalid hex digit")?;
+        let lo = (chunk[1] as char).to_digit(16).ok_or("invalid hex digit")?;
+        out[i] = ((hi << 4) | lo) as u8;
+    }
+
+    Ok(())
+}
+
+macro_rules! impl_fixed_width_serde {
+    ($($len:expr),+ $(,)*) => {
+        $(
+            #[cfg(feature = "serialize")]
+            impl serde::Serialize for Token<[u8; $len]> {
+                fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+                where
+                    Ser: Serializer,
+                {
+                    #[cfg(feature = "std")]
+                    {
+                        if serializer.is_human_readable() {
+                            return serializer.serialize_str(&hex_encode(&self.0));
+                        }
+                    }
+
+                    serializer.serialize_bytes(&self.0)
+                }
+            }
+
+            #[cfg(feature = "deserialize")]
+            impl<'de> serde::Deserialize<'de> for Token<[u8; $len]> {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    struct ArrayVisitor;
+
+                    impl<'de> serde::de::Visitor<'de> for ArrayVisitor {
+                        type Value = [u8; $len];
+
+                        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            write!(f, "{} bytes, as a byte string or hex string", $len)
+                        }
+
+                        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                        where
+                            E: serde::de::Error,
+                        {
+                            if v.len() != $len {
+                                return Err(E::invalid_length(v.len(), &self));
+                            }
+                            let mut out = [0u8; $len];
+                            out.copy_from_slice(v);
+                            Ok(out)
+                        }
+
+                        #[cfg(feature = "std")]
+                        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                        where
+                            E: serde::de::Error,
+                        {
+                            let mut out = [0u8; $len];
+                            hex_decode(v, &mut out).map_err(E::custom)?;
+                            Ok(out)
+                        }
+                    }
+
+                    if deserializer.is_human_readable() {
+                        deserializer.deserialize_str(ArrayVisitor).map(Token)
+                    } else {
+                        deserializer.deserialize_bytes(ArrayVisitor).map(Token)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_width_serde!(16, 20, 24, 32, 48, 64);
+
+/// Error returned when decoding a [`Token`] from its hex or base64 text
+/// form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseTokenError {
+    /// The encoded input's length didn't match the one the backing store's
+    /// size requires.
+    LengthMismatch {
+        /// The length (in encoded characters) a valid input would have had.
+        expected: usize,
+        /// The length (in encoded characters) the input actually had.
+        actual: usize,
+    },
+    /// The input contained a character outside of the expected alphabet.
+    InvalidCharacter,
+}
+
+impl fmt::Display for ParseTokenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseTokenError::LengthMismatch { expected, actual } => write!(
+                f,
+                "expected a token encoding of length {}, got {}",
+                expected, actual
+            ),
+            ParseTokenError::InvalidCharacter => {
+                f.write_str("invalid character in token encoding")
+            }
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTokenError {}
 
-#[cfg(feature = "serialize")]
-impl<T: serde::Serialize> serde::Serialize for Token<T> {
+const BASE64_URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+#[cfg(feature = "std")]
+fn base64_encode(bytes: &[u8]) -> std::string::String {
+    let mut out = std::string::String::with_capacity((bytes.len() * 4).div_ceil(3));
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_URL_SAFE_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_URL_SAFE_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        if chunk.len() > 1 {
+            out.push(BASE64_URL_SAFE_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char);
+        }
+        if chunk.len() > 2 {
+            out.push(BASE64_URL_SAFE_ALPHABET[(b2 & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+fn base64_decode_char(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'-' => Some(62),
+        b'_' => Some(63),
+        _ => None,
+    }
+}
+
+fn base64_decode(s: &str, out: &mut [u8]) -> Result<(), ParseTokenError> {
+    let expected_len = (out.len() * 4).div_ceil(3);
+    if s.len() != expected_len {
+        return Err(ParseTokenError::LengthMismatch {
+            expected: expected_len,
+            actual: s.len(),
+        });
+    }
+
+    let bytes = s.as_bytes();
+    let mut out_i = 0;
+    for chunk in bytes.chunks(4) {
+        let c0 = base64_decode_char(chunk[0]).ok_or(ParseTokenError::InvalidCharacter)?;
+        let c1 = base64_decode_char(chunk[1]).ok_or(ParseTokenError::InvalidCharacter)?;
+        out[out_i] = (c0 << 2) | (c1 >> 4);
+        out_i += 1;
+
+        if let Some(&b2) = chunk.get(2) {
+            let c2 = base64_decode_char(b2).ok_or(ParseTokenError::InvalidCharacter)?;
+            if out_i < out.len() {
+                out[out_i] = (c1 << 4) | (c2 >> 2);
+                out_i += 1;
+            }
+
+            if let Some(&b3) = chunk.get(3) {
+                let c3 = base64_decode_char(b3).ok_or(ParseTokenError::InvalidCharacter)?;
+                if out_i < out.len() {
+                    out[out_i] = (c2 << 6) | c3;
+                    out_i += 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl<S: AsRef<[u8]>> Token<S> {
+    /// Encodes the token as a lower-case hex string.
+    #[cfg(feature = "std")]
+    pub fn to_hex(&self) -> std::string::String {
+        hex_encode(self.0.as_ref())
+    }
+
+    /// Encodes the token as URL-safe, unpadded base64.
+    #[cfg(feature = "std")]
+    pub fn to_base64(&self) -> std::string::String {
+        base64_encode(self.0.as_ref())
+    }
+}
+
+macro_rules! impl_fixed_width_encoding {
+    ($($len:expr),+ $(,)*) => {
+        $(
+            impl Token<[u8; $len]> {
+                /// Decodes a token from a hex string, rejecting inputs whose
+                /// decoded length doesn't match the backing store's size.
+                pub fn from_hex(s: &str) -> Result<Self, ParseTokenError> {
+                    if s.len() != $len * 2 {
+                        return Err(ParseTokenError::LengthMismatch {
+                            expected: $len * 2,
+                            actual: s.len(),
+                        });
+                    }
+                    let mut out = [0u8; $len];
+                    hex_decode(s, &mut out).map_err(|_| ParseTokenError::InvalidCharacter)?;
+                    Ok(Token(out))
+                }
+
+                /// Decodes a token from URL-safe, unpadded base64.
+                pub fn from_base64(s: &str) -> Result<Self, ParseTokenError> {
+                    let mut out = [0u8; $len];
+                    base64_decode(s, &mut out)?;
+                    Ok(Token(out))
+                }
+            }
+
+            impl core::str::FromStr for Token<[u8; $len]> {
+                type Err = ParseTokenError;
+
+                #[inline]
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::from_hex(s)
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_width_encoding!(16, 20, 24, 32, 48, 64);
+
+impl<T: Hash> Hash for Token<T> {
     #[inline]
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+/// With the `zeroize` feature enabled, a `Token<S>` with a plain, `Copy`
+/// backing store ([u8; N] being the common case) has its bytes overwritten
+/// with zeros (via a volatile, non-elided write) as soon as it's dropped,
+/// so a leaked token doesn't linger in freed memory once it goes out of
+/// scope -- covering `generate`, `generate_with`, `create` and
+/// `from_hex`/`from_base64` alike, since the wipe happens in `Drop`, not at
+/// construction time. It does *not* protect intermediate stack copies made
+/// while constructing one (e.g. the temporary array `from_hex` decodes into
+/// before moving it into the returned `Token`).
+///
+/// For a heap-backed store such as `Token<String>` or `Token<Vec<u8>>`
+/// (both constructible via `create`), zeroing the raw bytes here would
+/// stomp over the pointer/length/capacity fields *before* `String`'s or
+/// `Vec`'s own `Drop` has run and freed the real allocation, corrupting it
+/// into a dangling/null pointer -- instant UB on deallocation. Rather than
+/// risk that, this impl checks [`core::mem::needs_drop`] and leaves
+/// anything with drop glue of its own alone (its backing buffer is freed
+/// normally, just not wiped).
+///
+/// `Drop` impls may not add a bound that `Token<S>`'s own declaration
+/// (`pub struct Token<S>(S);`) doesn't already have, so this can't be
+/// spelled as `impl<S: Zeroize> Drop for Token<S>` (E0367), nor can it be
+/// specialized per array length like the rest of this series's
+/// `impl_fixed_width_*!` macros (`impl Drop for Token<[u8; N]>` is E0366,
+/// "Drop impls cannot be specialized"). `needs_drop` sidesteps both: it's a
+/// `const fn` that doesn't require a trait bound on `S` at all.
+#[cfg(feature = "zeroize")]
+impl<S> Drop for Token<S> {
+    fn drop(&mut self) {
+        if core::mem::needs_drop::<S>() {
+            return;
+        }
+
+        let ptr = &mut self.0 as *mut S as *mut u8;
+        for i in 0..core::mem::size_of::<S>() {
+            unsafe {
+                core::ptr::write_volatile(ptr.add(i), 0);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+use self::digest::Digest;
+#[cfg(feature = "digest")]
+use self::generic_array::GenericArray;
+#[cfg(feature = "digest")]
+use self::sha2::Sha256;
+
+/// Length, in bytes, of the random salt mixed into a [`TokenDigest`].
+#[cfg(feature = "digest")]
+const SALT_LEN: usize = 16;
+
+/// A salted digest of a [`Token`], suitable for storing at rest.
+///
+/// Server-side session stores should never persist the raw token -- only a
+/// digest, the same way a password is stored as a hash rather than in the
+/// clear. A `TokenDigest` is `D::digest(salt || token)` for a random salt,
+/// and an incoming token is checked against it with
+/// [`verify`](TokenDigest::verify), which recomputes the hash and compares
+/// it in constant time. `D` is any `digest::Digest` backend; it defaults to
+/// SHA-256.
+#[cfg(feature = "digest")]
+#[derive(Clone)]
+pub struct TokenDigest<D: Digest = Sha256> {
+    salt: [u8; SALT_LEN],
+    hash: GenericArray<u8, D::OutputSize>,
+}
+
+#[cfg(feature = "digest")]
+impl<D: Digest> TokenDigest<D> {
+    fn hash_with_salt(salt: &[u8], token_bytes: &[u8]) -> GenericArray<u8, D::OutputSize> {
+        let mut hasher = D::new();
+        hasher.update(salt);
+        hasher.update(token_bytes);
+        hasher.finalize()
+    }
+
+    /// Hashes and salts `token` using the supplied cryptographically secure
+    /// RNG to generate the salt.
+    ///
+    /// This is the `no_std`-friendly entry point, mirroring
+    /// [`Token::generate_with`].
+    pub fn of_with<R, S>(rng: &mut R, token: &Token<S>) -> TokenDigest<D>
     where
-        S: Serializer,
+        R: RngCore + CryptoRng,
+        S: AsRef<[u8]>,
     {
-        self.0.serialize(serializer)
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+        let hash = Self::hash_with_salt(&salt, token.reveal_bytes());
+
+        TokenDigest { salt, hash }
+    }
+
+    /// Recomputes the salted hash of `token` and compares it against this
+    /// digest in constant time.
+    pub fn verify<S: AsRef<[u8]>>(&self, token: &Token<S>) -> bool {
+        let recomputed = Self::hash_with_salt(&self.salt, token.reveal_bytes());
+
+        constant_time_eq(&self.hash, &recomputed)
     }
 }
 
-#[cfg(feature = "deserialize")]
-impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Token<T> {
-    #[inline]
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+// `of` lives in a dedicated `impl TokenDigest<Sha256>` rather than the
+// generic `impl<D: Digest> TokenDigest<D>` above: `TokenDigest`'s `= Sha256`
+// default type parameter only kicks in where a concrete type is otherwise
+// required (e.g. a `let` binding's annotation), not when resolving which
+// inherent impl an unqualified `TokenDigest::of(&token)` call picks. Pinning
+// `of` to the concrete `Sha256` instantiation lets the common case infer
+// without a turbofish; callers using another `Digest` backend go through
+// `of_with`, specifying `D` via the binding they assign into.
+#[cfg(feature = "digest")]
+impl TokenDigest<Sha256> {
+    /// Hashes and salts `token` using the system's default CSPRNG.
+    ///
+    /// Requires the `getrandom` feature -- see [`Token::generate`]. For a
+    /// digest backend other than SHA-256, use
+    /// [`of_with`](TokenDigest::of_with).
+    #[cfg(feature = "getrandom")]
+    pub fn of<S: AsRef<[u8]>>(token: &Token<S>) -> TokenDigest<Sha256> {
+        Self::of_with(&mut rand::thread_rng(), token)
+    }
+}
+
+#[cfg(all(feature = "digest", feature = "serialize"))]
+impl serde::Serialize for TokenDigest<Sha256> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
     where
-        D: Deserializer<'de>,
+        Ser: Serializer,
     {
-        T::deserialize(deserializer).map(Token)
+        let mut bytes = [0u8; SALT_LEN + 32];
+        bytes[..SALT_LEN].copy_from_slice(&self.salt);
+        bytes[SALT_LEN..].copy_from_slice(&self.hash);
+
+        #[cfg(feature = "std")]
+        {
+            if serializer.is_human_readable() {
+                return serializer.serialize_str(&hex_encode(&bytes));
+            }
+        }
+
+        serializer.serialize_bytes(&bytes)
     }
 }
 
-impl<T: Hash> Hash for Token<T> {
-    #[inline]
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.hash(state);
+#[cfg(all(feature = "digest", feature = "deserialize"))]
+impl<'de> serde::Deserialize<'de> for TokenDigest<Sha256> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DigestVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DigestVisitor {
+            type Value = [u8; SALT_LEN + 32];
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} bytes, as a byte string or hex string", SALT_LEN + 32)
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if v.len() != SALT_LEN + 32 {
+                    return Err(E::invalid_length(v.len(), &self));
+                }
+                let mut out = [0u8; SALT_LEN + 32];
+                out.copy_from_slice(v);
+                Ok(out)
+            }
+
+            #[cfg(feature = "std")]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let mut out = [0u8; SALT_LEN + 32];
+                hex_decode(v, &mut out).map_err(E::custom)?;
+                Ok(out)
+            }
+        }
+
+        let bytes = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DigestVisitor)?
+        } else {
+            deserializer.deserialize_bytes(DigestVisitor)?
+        };
+        let mut salt = [0u8; SALT_LEN];
+        salt.copy_from_slice(&bytes[..SALT_LEN]);
+
+        Ok(TokenDigest {
+            salt,
+            hash: GenericArray::clone_from_slice(&bytes[SALT_LEN..]),
+        })
     }
 }
 
@@ -190,6 +774,7 @@ mod tests {
     #[cfg(feature = "std")]
     use std::collections::hash_map::DefaultHasher;
 
+    #[cfg(feature = "getrandom")]
     #[test]
     fn test_token_eq() {
         let tok: Token<[u8; 32]> = Token::generate();
@@ -198,6 +783,7 @@ mod tests {
         assert!(tok != tok2);
     }
 
+    #[cfg(feature = "getrandom")]
     #[test]
     fn test_token_ord() {
         let tok: Token<[u8; 32]> = Token::generate();
@@ -206,7 +792,7 @@ mod tests {
         assert!(tok < tok2 || tok > tok2)
     }
 
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", feature = "getrandom"))]
     #[test]
     fn test_hash() {
         let tok1: Token<[u8; 32]> = Token::generate();